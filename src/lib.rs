@@ -1,11 +1,12 @@
 use std::default::Default;
+use std::rc::Rc;
 
 use drawille::Canvas as BrailleCanvas;
 use drawille::PixelColor;
 
 pub mod scale;
 
-use scale::Scale;
+use scale::{Scale, ScaleKind};
 
 #[derive(Clone)]
 pub struct RGB8 {
@@ -34,6 +35,16 @@ impl RGB8 {
     }
 }
 
+/// A single plotted series: its shape, color, data points, optional legend label and optional
+/// per-point error magnitudes (used by `Shape::ErrorBars`).
+type Series = (
+    Shape,
+    Option<RGB8>,
+    Vec<(f32, f32)>,
+    Option<String>,
+    Option<Vec<f32>>,
+);
+
 /// How the chart will do the ranging on axes
 #[derive(PartialEq)]
 enum ChartRangeMethod {
@@ -55,22 +66,32 @@ pub struct Chart {
     xmax: f32,
     /// The type of x axis ranging used
     x_ranging: ChartRangeMethod,
+    /// The kind of scale (linear or logarithmic) used on the x axis.
+    x_scale: ScaleKind,
     /// Y-axis start value (potentially calculated automatically).
     ymin: f32,
     /// Y-axis end value (potentially calculated automatically).
     ymax: f32,
     /// The type of y axis ranging used
     y_ranging: ChartRangeMethod,
-    /// Data points to plot
+    /// The kind of scale (linear or logarithmic) used on the y axis.
+    y_scale: ScaleKind,
+    /// Data points that will be captured by the next `lineplot` call.
     data_points: Vec<(f32, f32)>,
-    /// Collection of shapes to be presented on the canvas.
-    appearance: Vec<(Shape, Option<RGB8>)>,
+    /// Label that will be captured by the next `lineplot` call.
+    pending_label: Option<String>,
+    /// Error magnitudes that will be captured by the next `lineplot` call.
+    pending_errors: Option<Vec<f32>>,
+    /// Collection of series to be presented on the canvas.
+    appearance: Vec<Series>,
     /// If true, show x and y axis.
     show_axis: bool,
     /// Function to apply to X-axis ticks.
     xtick: Option<Box<dyn Fn(f32) -> String>>,
     /// Function to apply to Y-axis ticks.
     ytick: Option<Box<dyn Fn(f32) -> String>>,
+    /// Category names for a categorical x-axis, indexed by the integer x position.
+    categories: Option<Vec<String>>,
     /// Underlying canvas object.
     canvas: BrailleCanvas,
 }
@@ -82,12 +103,32 @@ pub enum Shape {
     Lines,
     Steps,
     Bars,
+    /// A mathematical function sampled lazily across the canvas width, e.g.
+    /// `Shape::Continuous(Rc::new(|x| x.sin() / x))`. Has no data points of its own to
+    /// auto-range from, so it needs an explicit domain: build the chart with
+    /// `Chart::with_range` (or call `.data()` with points spanning the x and y range to show)
+    /// rather than `Chart::default()`/`Chart::new()`. A single point collapses both ranges and
+    /// nothing is drawn.
+    Continuous(Rc<dyn Fn(f32) -> f32>),
+    /// A histogram over the x-values of the data, bucketed into `bins` equal-width bars
+    /// whose height is the sample count in that bucket.
+    Histogram { bins: usize },
+    /// A box-and-whisker plot of the five-number summary (min, Q1, median, Q3, max) of the
+    /// y-values. Several box plots are spaced side by side along x when multiple series use it.
+    BoxPlot,
+    /// Each point drawn as a vertical bar from `y - err` to `y + err` with short caps, using the
+    /// error magnitudes set via `Plot::errors`.
+    ErrorBars,
 }
 
 /// Provides an interface for drawing plots.
 pub trait Plot<'a> {
     /// Sets the data points that will be plotted
     fn data(&'a mut self, data_points: Vec<(f32, f32)>) -> &'a mut Chart;
+    /// Sets the label of the next series, shown in the legend.
+    fn label(&'a mut self, label: &str) -> &'a mut Chart;
+    /// Sets the per-point error magnitudes used by `Shape::ErrorBars` for the next series.
+    fn errors(&'a mut self, errors: Vec<f32>) -> &'a mut Chart;
     /// Draws a [line chart](https://en.wikipedia.org/wiki/Line_chart) of points connected by straight line segments.
     fn lineplot(&'a mut self, shape: Shape, color: Option<RGB8>) -> &'a mut Chart;
     /// Hides the x and y axis.
@@ -119,16 +160,21 @@ impl<'a> Chart {
             xmin: f32::INFINITY,
             xmax: f32::NEG_INFINITY,
             x_ranging: ChartRangeMethod::AutoRange,
+            x_scale: ScaleKind::Linear,
             ymin: f32::INFINITY,
             ymax: f32::NEG_INFINITY,
             y_ranging: ChartRangeMethod::AutoRange,
+            y_scale: ScaleKind::Linear,
             width,
             height,
             data_points: Vec::new(),
+            pending_label: None,
+            pending_errors: None,
             appearance: Vec::new(),
             show_axis: true,
             xtick: None,
             ytick: None,
+            categories: None,
             canvas: BrailleCanvas::new(width, height),
         }
     }
@@ -151,16 +197,21 @@ impl<'a> Chart {
             xmin,
             xmax,
             x_ranging: ChartRangeMethod::FixedRange,
+            x_scale: ScaleKind::Linear,
             ymin,
             ymax,
             y_ranging: ChartRangeMethod::FixedRange,
+            y_scale: ScaleKind::Linear,
             width,
             height,
             data_points: Vec::new(),
+            pending_label: None,
+            pending_errors: None,
             appearance: Vec::new(),
             show_axis: true,
             xtick: None,
             ytick: None,
+            categories: None,
             canvas: BrailleCanvas::new(width, height),
         }
     }
@@ -218,13 +269,28 @@ impl<'a> Chart {
 
         if let Some(idx) = frame.find('\n') {
             frame.insert_str(idx, &format!(" {}", self.format_yaxis_tick(self.ymax)));
-            frame.push_str(&format!(
-                " {0}\n{1: <width$}{2}\n",
-                self.format_yaxis_tick(self.ymin),
-                xmin,
-                xmax,
-                width = (self.width as usize) / 2 - xmax.chars().count(),
-            ));
+            frame.push_str(&format!(" {}\n", self.format_yaxis_tick(self.ymin)));
+            if let Some(labels) = self.format_xaxis_labels() {
+                frame.push_str(&labels);
+                frame.push('\n');
+            } else {
+                frame.push_str(&format!(
+                    "{0: <width$}{1}\n",
+                    xmin,
+                    xmax,
+                    width = (self.width as usize) / 2 - xmax.chars().count(),
+                ));
+            }
+        }
+
+        for (_, color, _, label, _) in &self.appearance {
+            if let Some(label) = label {
+                let marker = match color {
+                    Some(color) => format!("\x1b[38;2;{};{};{}m\u{25a0}\x1b[0m", color.r, color.g, color.b),
+                    None => "\u{25a0}".to_string(),
+                };
+                frame.push_str(&format!("{} {}\n", marker, label));
+            }
         }
     }
 
@@ -241,8 +307,8 @@ impl<'a> Chart {
 
     /// Show axis at x = 0 and y = 0 if in view
     pub fn null_axis(&mut self) {
-        let x_scale = Scale::new(self.xmin..self.xmax, 0.0..self.width as f32);
-        let y_scale = Scale::new(self.ymin..self.ymax, 0.0..self.height as f32);
+        let x_scale = Scale::with_kind(self.xmin..self.xmax, 0.0..self.width as f32, self.x_scale);
+        let y_scale = Scale::with_kind(self.ymin..self.ymax, 0.0..self.height as f32, self.y_scale);
 
         if self.xmin <= 0.0 && self.xmax >= 0.0 {
             self.vline(x_scale.linear(0.0) as u32);
@@ -261,19 +327,26 @@ impl<'a> Chart {
         }
     }
 
-    fn figure(&mut self, shape: &Shape, color: &Option<RGB8>) {
-        let x_scale = Scale::new(self.xmin..self.xmax, 0.0..self.width as f32);
-        let y_scale = Scale::new(self.ymin..self.ymax, 0.0..self.height as f32);
+    fn figure(
+        &mut self,
+        shape: &Shape,
+        color: &Option<RGB8>,
+        data_points: &[(f32, f32)],
+        errors: &Option<Vec<f32>>,
+        box_index: usize,
+        box_count: usize,
+    ) {
+        let x_scale = Scale::with_kind(self.xmin..self.xmax, 0.0..self.width as f32, self.x_scale);
+        let y_scale = Scale::with_kind(self.ymin..self.ymax, 0.0..self.height as f32, self.y_scale);
 
         // translate (x, y) points into screen coordinates
-        let points: Vec<_> = self
-            .data_points
+        let points: Vec<_> = data_points
             .iter()
             .filter_map(|(x, y)| {
-                let i = x_scale.linear(*x).round() as u32;
-                let j = y_scale.linear(*y).round() as u32;
-                if i <= self.width && j <= self.height {
-                    Some((i, self.height - j))
+                let i = x_scale.linear(*x).round();
+                let j = y_scale.linear(*y).round();
+                if i.is_finite() && j.is_finite() && (i as u32) <= self.width && (j as u32) <= self.height {
+                    Some((i as u32, self.height - j as u32))
                 } else {
                     None
                 }
@@ -282,6 +355,165 @@ impl<'a> Chart {
 
         // display segments
         match shape {
+            Shape::Continuous(f) => {
+                let mut last: Option<(u32, u32)> = None;
+                for i in 0..=self.width {
+                    let t = i as f32 / self.width as f32;
+                    let x = if self.x_scale == ScaleKind::Log10 {
+                        10f32.powf(self.xmin.log10() + t * (self.xmax.log10() - self.xmin.log10()))
+                    } else {
+                        self.xmin + t * (self.xmax - self.xmin)
+                    };
+                    let y = f(x);
+
+                    if !y.is_finite() {
+                        last = None;
+                        continue;
+                    }
+
+                    let j = y_scale.linear(y).round();
+                    if !j.is_finite() || j < 0.0 || j > self.height as f32 {
+                        last = None;
+                        continue;
+                    }
+
+                    let point = (i, self.height - j as u32);
+                    if let Some((x1, y1)) = last {
+                        self.render_line(x1, y1, point.0, point.1, color);
+                    }
+                    last = Some(point);
+                }
+            }
+            Shape::Histogram { bins } => {
+                let bins = (*bins).max(1);
+                let range = self.xmax - self.xmin;
+                let mut counts = vec![0u32; bins];
+                for (x, _) in data_points {
+                    let idx = if range > 0.0 {
+                        (((x - self.xmin) / range) * bins as f32).clamp(0.0, bins as f32 - 1.0) as usize
+                    } else {
+                        0
+                    };
+                    counts[idx] += 1;
+                }
+
+                let max_count = counts.iter().copied().max().unwrap_or(0);
+                if max_count == 0 {
+                    return;
+                }
+
+                // When the histogram is the chart's only series, the printed y-axis labels
+                // come from `self.ymin`/`self.ymax`, so make those permanently reflect the
+                // bucket counts instead of the raw sample y-values. With other series sharing
+                // the chart, leave the shared range alone and only borrow it for this drawing.
+                let histogram_only = self.appearance.len() == 1;
+                let saved_ymax = self.ymax;
+                if histogram_only {
+                    self.ymin = 0.0;
+                }
+                self.ymax = max_count as f32;
+                let y_scale = Scale::with_kind(0.0..self.ymax, 0.0..self.height as f32, self.y_scale);
+
+                let bin_width = self.width as f32 / bins as f32;
+                for (i, &count) in counts.iter().enumerate() {
+                    if count == 0 {
+                        continue;
+                    }
+                    let x1 = (i as f32 * bin_width).round() as u32;
+                    let x2 = ((i + 1) as f32 * bin_width).round() as u32;
+                    let y1 = self.height - y_scale.linear(count as f32).round() as u32;
+
+                    self.render_line(x1, y1, x2, y1, color);
+                    self.render_line(x1, y1, x1, self.height, color);
+                    self.render_line(x2, y1, x2, self.height, color);
+                }
+                if !histogram_only {
+                    self.ymax = saved_ymax;
+                }
+            }
+            Shape::BoxPlot => {
+                let mut ys: Vec<f32> = data_points.iter().map(|&(_, y)| y).collect();
+                if ys.is_empty() {
+                    return;
+                }
+                // NaN sorts greatest so a stray NaN sample can't make sorting panic.
+                ys.sort_by(|a, b| a.is_nan().cmp(&b.is_nan()).then_with(|| a.total_cmp(b)));
+
+                let min = ys[0];
+                let max = ys[ys.len() - 1];
+                let q1 = quantile(&ys, 0.25);
+                let median = quantile(&ys, 0.5);
+                let q3 = quantile(&ys, 0.75);
+
+                let slot_width = self.width as f32 / box_count.max(1) as f32;
+                let center = (box_index as f32 + 0.5) * slot_width;
+                let half_width = (slot_width * 0.3).max(1.0);
+                let x1 = (center - half_width).round() as u32;
+                let x2 = (center + half_width).round() as u32;
+                let xc = center.round() as u32;
+
+                let to_screen_y = |value: f32| -> Option<u32> {
+                    let j = y_scale.linear(value).round();
+                    if j.is_finite() {
+                        Some(self.height - j.clamp(0.0, self.height as f32) as u32)
+                    } else {
+                        None
+                    }
+                };
+                let y_min = to_screen_y(min);
+                let y_q1 = to_screen_y(q1);
+                let y_median = to_screen_y(median);
+                let y_q3 = to_screen_y(q3);
+                let y_max = to_screen_y(max);
+
+                match (y_min, y_q1, y_median, y_q3, y_max) {
+                    (Some(y_min), Some(y_q1), Some(y_median), Some(y_q3), Some(y_max)) => {
+                        // box from Q1 to Q3, with the median line through it
+                        self.render_line(x1, y_q3, x2, y_q3, color);
+                        self.render_line(x1, y_q1, x2, y_q1, color);
+                        self.render_line(x1, y_q3, x1, y_q1, color);
+                        self.render_line(x2, y_q3, x2, y_q1, color);
+                        self.render_line(x1, y_median, x2, y_median, color);
+
+                        // whiskers with small caps at min/max
+                        self.render_line(xc, y_q3, xc, y_max, color);
+                        self.render_line(xc, y_q1, xc, y_min, color);
+                        let cap_half = (half_width * 0.5).max(1.0);
+                        let cap_x1 = (xc as f32 - cap_half).round() as u32;
+                        let cap_x2 = (xc as f32 + cap_half).round() as u32;
+                        self.render_line(cap_x1, y_max, cap_x2, y_max, color);
+                        self.render_line(cap_x1, y_min, cap_x2, y_min, color);
+                    }
+                    _ => {
+                        // A degenerate y domain (e.g. ymin == ymax) makes every quantile
+                        // translate to NaN; draw a single flat marker instead of letting a
+                        // NaN-as-0 cast paint a spurious full-height stripe.
+                        let y = self.height / 2;
+                        self.render_line(x1, y, x2, y, color);
+                    }
+                }
+            }
+            Shape::ErrorBars => {
+                for (idx, &(x, y)) in data_points.iter().enumerate() {
+                    let err = errors.as_ref().and_then(|e| e.get(idx)).copied().unwrap_or(0.0);
+
+                    let i = x_scale.linear(x).round();
+                    let j_lo = y_scale.linear(y - err).round();
+                    let j_hi = y_scale.linear(y + err).round();
+                    if !i.is_finite() || !j_lo.is_finite() || !j_hi.is_finite() || (i as u32) > self.width {
+                        continue;
+                    }
+
+                    let xi = i as u32;
+                    let y_bottom = self.height - j_lo.clamp(0.0, self.height as f32) as u32;
+                    let y_top = self.height - j_hi.clamp(0.0, self.height as f32) as u32;
+
+                    self.render_line(xi, y_top, xi, y_bottom, color);
+                    let cap_half = 1;
+                    self.render_line(xi.saturating_sub(cap_half), y_top, xi + cap_half, y_top, color);
+                    self.render_line(xi.saturating_sub(cap_half), y_bottom, xi + cap_half, y_bottom, color);
+                }
+            }
             Shape::Points => {
                 for (x, y) in points {
                     self.canvas.set(x, y);
@@ -302,6 +534,25 @@ impl<'a> Chart {
                     self.render_line(x1, y1, x1, y2, color);
                 }
             }
+            Shape::Bars if self.categories.is_some() => {
+                let slot_width = (x_scale.linear(1.0) - x_scale.linear(0.0)).abs();
+                let half_width = (slot_width * 0.4).max(1.0);
+                for &(x, y) in data_points {
+                    let i = x_scale.linear(x).round();
+                    let j = y_scale.linear(y).round();
+                    if !i.is_finite() || !j.is_finite() {
+                        continue;
+                    }
+
+                    let x1 = (i - half_width).round() as u32;
+                    let x2 = (i + half_width).round() as u32;
+                    let y1 = self.height - j.clamp(0.0, self.height as f32) as u32;
+
+                    self.render_line(x1, y1, x2, y1, color);
+                    self.render_line(x1, y1, x1, self.height, color);
+                    self.render_line(x2, y1, x2, self.height, color);
+                }
+            }
             Shape::Bars => {
                 for pair in points.windows(2) {
                     let (x1, y1) = pair[0];
@@ -318,8 +569,18 @@ impl<'a> Chart {
 
     // Show figures.
     pub fn figures(&mut self) {
-        for (shape, color) in self.appearance.clone() {
-            self.figure(&shape, &color)
+        let box_count = self
+            .appearance
+            .iter()
+            .filter(|(shape, ..)| matches!(shape, Shape::BoxPlot))
+            .count();
+        let mut box_index = 0;
+        for (shape, color, data_points, _label, errors) in self.appearance.clone() {
+            let is_box_plot = matches!(shape, Shape::BoxPlot);
+            self.figure(&shape, &color, &data_points, &errors, box_index, box_count);
+            if is_box_plot {
+                box_index += 1;
+            }
         }
     }
 
@@ -331,14 +592,55 @@ impl<'a> Chart {
     fn format_xaxis_tick(&self, value: f32) -> String {
         if let Some(ref f) = self.xtick {
             f(value)
+        } else if let Some(category) = self.category_at(value) {
+            category
+        } else if self.x_scale == ScaleKind::Log10 {
+            format_log_tick(value)
         } else {
             format!("{:.1}", value)
         }
     }
 
+    /// Returns the category name for an integer x position, if categories are set.
+    fn category_at(&self, value: f32) -> Option<String> {
+        let categories = self.categories.as_ref()?;
+        if value < 0.0 || value.round() != value {
+            return None;
+        }
+        categories.get(value as usize).cloned()
+    }
+
+    /// Builds a line with every category name centered under its bar slot, for use in place
+    /// of the plain xmin/xmax labels when the x-axis is categorical.
+    fn format_xaxis_labels(&self) -> Option<String> {
+        let categories = self.categories.as_ref()?;
+        // The canvas packs 2 points per braille character column, so the printed row is
+        // `self.width / 2` characters wide, not `self.width` — match that column count here
+        // the same way the xmin/xmax fallback in `show_num_label` does.
+        let columns = self.width as f32 / 2.0;
+        let x_scale = Scale::with_kind(self.xmin..self.xmax, 0.0..columns, self.x_scale);
+
+        let mut row = vec![' '; self.width as usize / 2 + 1];
+        for (i, category) in categories.iter().enumerate() {
+            let center = x_scale.linear(i as f32).round();
+            if !center.is_finite() {
+                continue;
+            }
+            let start = (center as isize - category.chars().count() as isize / 2).max(0) as usize;
+            for (offset, ch) in category.chars().enumerate() {
+                if let Some(slot) = row.get_mut(start + offset) {
+                    *slot = ch;
+                }
+            }
+        }
+        Some(row.into_iter().collect())
+    }
+
     fn format_yaxis_tick(&self, value: f32) -> String {
         if let Some(ref f) = self.ytick {
             f(value)
+        } else if self.y_scale == ScaleKind::Log10 {
+            format_log_tick(value)
         } else {
             format!("{:.1}", value)
         }
@@ -356,16 +658,40 @@ impl<'a> Chart {
         self
     }
 
+    /// Makes the x-axis categorical: each category is mapped to an evenly-spaced integer x
+    /// position (`0`, `1`, `2`, ...) and `format_xaxis_tick` shows its name instead of the number.
+    pub fn set_categories(mut self, categories: Vec<String>) -> Self {
+        self.categories = Some(categories);
+        self
+    }
+
+    /// Switches the x axis to a logarithmic (base 10) scale.
+    pub fn set_xscale_log(mut self) -> Self {
+        self.x_scale = ScaleKind::Log10;
+        self
+    }
+
+    /// Switches the y axis to a logarithmic (base 10) scale.
+    pub fn set_yscale_log(mut self) -> Self {
+        self.y_scale = ScaleKind::Log10;
+        self
+    }
+
     fn rescale_x(&mut self) {
+        // A log-scaled domain must stay strictly positive, so non-positive samples are
+        // excluded here rather than left to poison `domain.start.log10()`/`domain.end.log10()`.
+        let log_x = self.x_scale == ScaleKind::Log10;
         let xmin = self
-            .data_points
+            .appearance
             .iter()
-            .map(|&(x, _)| x)
+            .flat_map(|(_, _, data_points, ..)| data_points.iter().map(|&(x, _)| x))
+            .filter(|x| !log_x || *x > 0.0)
             .fold(f32::INFINITY, |min_x, x| min_x.min(x));
         let xmax = self
-            .data_points
+            .appearance
             .iter()
-            .map(|&(x, _)| x)
+            .flat_map(|(_, _, data_points, ..)| data_points.iter().map(|&(x, _)| x))
+            .filter(|x| !log_x || *x > 0.0)
             .fold(f32::NEG_INFINITY, |max_x, x| max_x.max(x));
 
         self.xmin = f32::min(self.xmin, xmin);
@@ -373,15 +699,27 @@ impl<'a> Chart {
     }
 
     fn rescale_y(&mut self) {
+        // Same reasoning as `rescale_x`: a log-scaled y-domain must stay strictly positive.
+        let log_y = self.y_scale == ScaleKind::Log10;
         let ymin = self
-            .data_points
+            .appearance
             .iter()
-            .map(|&(_, y)| y)
+            .flat_map(|(_, _, data_points, _, errors)| {
+                data_points.iter().enumerate().map(move |(i, &(_, y))| {
+                    y - errors.as_ref().and_then(|e| e.get(i)).copied().unwrap_or(0.0)
+                })
+            })
+            .filter(|y| !log_y || *y > 0.0)
             .fold(f32::INFINITY, |min_y, y| min_y.min(y));
         let ymax = self
-            .data_points
+            .appearance
             .iter()
-            .map(|&(_, y)| y)
+            .flat_map(|(_, _, data_points, _, errors)| {
+                data_points.iter().enumerate().map(move |(i, &(_, y))| {
+                    y + errors.as_ref().and_then(|e| e.get(i)).copied().unwrap_or(0.0)
+                })
+            })
+            .filter(|y| !log_y || *y > 0.0)
             .fold(f32::NEG_INFINITY, |max_y, y| max_y.max(y));
 
         self.ymin = f32::min(self.ymin, ymin);
@@ -395,8 +733,21 @@ impl<'a> Plot<'a> for Chart {
         self
     }
 
+    fn label(&'a mut self, label: &str) -> &'a mut Chart {
+        self.pending_label = Some(label.to_string());
+        self
+    }
+
+    fn errors(&'a mut self, errors: Vec<f32>) -> &'a mut Chart {
+        self.pending_errors = Some(errors);
+        self
+    }
+
     fn lineplot(&'a mut self, shape: Shape, color: Option<RGB8>) -> &'a mut Chart {
-        self.appearance.push((shape.clone(), color));
+        let label = self.pending_label.take();
+        let errors = self.pending_errors.take();
+        self.appearance
+            .push((shape.clone(), color, self.data_points.clone(), label, errors));
         if self.x_ranging == ChartRangeMethod::AutoRange {
             self.rescale_x();
         }
@@ -412,6 +763,31 @@ impl<'a> Plot<'a> for Chart {
     }
 }
 
+/// Linearly interpolated quantile `q` (in `0.0..=1.0`) of an already-sorted slice.
+fn quantile(sorted: &[f32], q: f32) -> f32 {
+    if sorted.len() == 1 {
+        return sorted[0];
+    }
+
+    let pos = q * (sorted.len() - 1) as f32;
+    let lower = pos.floor() as usize;
+    let upper = pos.ceil() as usize;
+    if lower == upper {
+        sorted[lower]
+    } else {
+        sorted[lower] + (sorted[upper] - sorted[lower]) * (pos - lower as f32)
+    }
+}
+
+/// Formats a logarithmic axis tick as a power of ten, e.g. `100.0` -> `1e2`.
+fn format_log_tick(value: f32) -> String {
+    if value <= 0.0 {
+        format!("{:.1}", value)
+    } else {
+        format!("1e{}", value.log10().round() as i32)
+    }
+}
+
 fn rgb_to_pixelcolor(rgb: &RGB8) -> PixelColor {
     PixelColor::TrueColor {
         r: rgb.r,
@@ -0,0 +1,48 @@
+use std::ops::Range;
+
+/// How a [`Scale`] maps values from its domain into its range.
+#[derive(Clone, Copy, PartialEq)]
+pub enum ScaleKind {
+    /// Evenly spaced values.
+    Linear,
+    /// Evenly spaced powers of ten. Values `<= 0` fall outside the domain.
+    Log10,
+}
+
+/// Maps values from one [`Range`] (the domain) onto another (the range).
+pub struct Scale {
+    domain: Range<f32>,
+    range: Range<f32>,
+    kind: ScaleKind,
+}
+
+impl Scale {
+    /// Creates a new linear `Scale` mapping `domain` onto `range`.
+    pub fn new(domain: Range<f32>, range: Range<f32>) -> Self {
+        Self::with_kind(domain, range, ScaleKind::Linear)
+    }
+
+    /// Creates a new `Scale` of the given `kind` mapping `domain` onto `range`.
+    pub fn with_kind(domain: Range<f32>, range: Range<f32>, kind: ScaleKind) -> Self {
+        Self { domain, range, kind }
+    }
+
+    /// Maps `x` from `domain` into `range`. For a `Log10` scale this is `NaN`
+    /// or infinite when `x` is outside the representable domain (`x <= 0`),
+    /// so callers should check `is_finite()` before using the result.
+    pub fn linear(&self, x: f32) -> f32 {
+        match self.kind {
+            ScaleKind::Linear => Self::map(x, self.domain.start, self.domain.end, &self.range),
+            ScaleKind::Log10 => Self::map(
+                x.log10(),
+                self.domain.start.log10(),
+                self.domain.end.log10(),
+                &self.range,
+            ),
+        }
+    }
+
+    fn map(x: f32, domain_start: f32, domain_end: f32, range: &Range<f32>) -> f32 {
+        range.start + (range.end - range.start) * (x - domain_start) / (domain_end - domain_start)
+    }
+}